@@ -1,8 +1,68 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
-use quote::quote;
-use syn::{parse_macro_input, ItemFn};
+use quote::{quote, quote_spanned};
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, FnArg, Ident, ItemFn, LitStr, ReturnType, Signature, Token};
+
+/// The arguments accepted by `#[action(...)]`, e.g. `path = "Greeting"`.
+struct ActionArgs {
+    path: Option<LitStr>,
+}
+
+impl Parse for ActionArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut path = None;
+
+        if !input.is_empty() {
+            let key: Ident = input.parse()?;
+            if key != "path" {
+                return Err(syn::Error::new_spanned(
+                    key,
+                    "unknown `#[action]` argument, expected `path`",
+                ));
+            }
+            input.parse::<Token![=]>()?;
+            path = Some(input.parse::<LitStr>()?);
+        }
+
+        Ok(ActionArgs { path })
+    }
+}
+
+/// Checks that the annotated function is a valid action handler: `async`, and
+/// taking exactly two typed parameters (`res: Res, req: Req`). Returns a
+/// targeted `compile_error!` pointing at the offending token when it isn't.
+fn validate_signature(sig: &Signature) -> Result<(), TokenStream> {
+    if sig.asyncness.is_none() {
+        return Err(TokenStream::from(
+            syn::Error::new_spanned(sig.fn_token, "action functions must be declared `async`")
+                .to_compile_error(),
+        ));
+    }
+
+    if sig.inputs.len() != 2 {
+        return Err(TokenStream::from(
+            syn::Error::new_spanned(
+                &sig.inputs,
+                "action functions must take exactly two parameters: `res: Res, req: Req`",
+            )
+            .to_compile_error(),
+        ));
+    }
+
+    for arg in &sig.inputs {
+        if let FnArg::Receiver(receiver) = arg {
+            return Err(TokenStream::from(
+                syn::Error::new_spanned(receiver, "action functions cannot take `self`")
+                    .to_compile_error(),
+            ));
+        }
+    }
+
+    Ok(())
+}
 
 /// The `#[action]` proc macro is used to create a new action.
 ///
@@ -28,24 +88,105 @@ use syn::{parse_macro_input, ItemFn};
 /// }
 /// ```
 ///
+/// By default `path()` returns the function name, but it can be overridden with
+/// `#[action(path = "Greeting")]`:
+///
+/// ```rust
+/// use russenger::prelude::*;
+///
+/// #[action(path = "Greeting")]
+/// async fn Main(res: russenger::prelude::Res, req: russenger::prelude::Req) {
+///     res.send(TextModel::new(&req.user, "Hello, welcome to our bot!")).await;
+/// }
+/// ```
+///
+/// The body of an `async fn` with the default `()` return type does not need to
+/// produce a `Result` itself; `?` works freely and the macro appends `Ok(())`
+/// for you. If you want the body to produce its own `Result` (for example an
+/// existing action that manually ends with `Ok(())`), declare `-> Result<()>`
+/// explicitly — the body is then used as-is and must return the `Result`
+/// itself.
+///
+/// Every action is also submitted to a crate-wide [`inventory`] registry under
+/// an `ActionEntry` carrying its resolved `path` and a constructor for it, so
+/// `russenger` can discover every `#[action]` at startup with
+/// `inventory::iter::<ActionEntry>()` instead of requiring it to be wired into
+/// the router by hand. This relies on `ActionEntry` being declared once, by
+/// the runtime, with `inventory::collect!(ActionEntry)`.
+///
 /// This macro simplifies the process of creating a new action by automatically generating the struct and implementing the `Action` trait for it.
 #[proc_macro_attribute]
-pub fn action(_attr: TokenStream, item: TokenStream) -> TokenStream {
-    // Parse the input function
+pub fn action(attr: TokenStream, item: TokenStream) -> TokenStream {
+    // Parse the macro arguments and the input function
+    let args = parse_macro_input!(attr as ActionArgs);
     let input = parse_macro_input!(item as ItemFn);
 
+    if let Err(err) = validate_signature(&input.sig) {
+        return err;
+    }
+
     // Extract function components
+    let attrs = input.attrs; // Doc comments, cfg, allow, etc.
+    // `cfg` must gate the struct AND its `impl Action` together, or one can
+    // vanish while the other still references it; `doc` is what makes the
+    // generated type show up nicely in docs. Function attributes like
+    // `#[inline]` or `#[tracing::instrument]` aren't legal on a struct or a
+    // trait impl, so those stay on `execute` below.
+    let struct_attrs: Vec<_> = attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc") || attr.path().is_ident("cfg"))
+        .cloned()
+        .collect();
     let sig = input.sig;
     let vis = input.vis; // Function visibility
     let ident = sig.ident; // Function name
     let inputs = sig.inputs; // Function parameters
+    let output = sig.output; // Function return type
     let block = input.block;
+    let block_span = block.span();
+
+    let path = args
+        .path
+        .map(|lit| lit.value())
+        .unwrap_or_else(|| ident.to_string());
+
+    // Generate a unit struct named after the function that implements `Action`,
+    // wiring the original body into `execute` and the resolved path into `path`.
+    // `quote_spanned!` keeps the body's original spans so borrow/await errors
+    // inside it point back to the user's code instead of this macro.
+    //
+    // Whether the body already produces a `Result` can only be known from the
+    // declared return type, not by guessing at the shape of its last
+    // statement: an `if`/`match`/`for`/`loop` tail is just as likely to be a
+    // `()`-valued guard clause as a value-producing expression. So the default
+    // `()` return type always gets `Ok(())` appended (any `()`-valued
+    // statement, including an early `?`, is allowed), and a function that
+    // wants to produce its own `Result` — including the old manual-`Ok(())`
+    // style — must say so explicitly with `-> Result<()>`.
+    let execute_body = match output {
+        ReturnType::Default => {
+            quote_spanned! {block_span=> Box::pin(async move { #block; Ok(()) }) }
+        }
+        ReturnType::Type(..) => quote_spanned! {block_span=> Box::pin(async move #block) },
+    };
 
-    // Generate a new function with the proper async wrapping
     let expanded = quote! {
-        #vis fn #ident(#inputs) -> std::pin::Pin<Box<dyn std::future::Future<Output = error::Result<()>>>> {
-            Box::pin(async move #block)
+        #(#struct_attrs)*
+        #vis struct #ident;
+
+        #(#struct_attrs)*
+        impl Action for #ident {
+            #(#attrs)*
+            fn execute(&self, #inputs) -> std::pin::Pin<Box<dyn std::future::Future<Output = error::Result<()>>>> {
+                #execute_body
+            }
+
+            fn path(&self) -> String {
+                #path.to_string()
+            }
         }
+
+        inventory::submit! { ActionEntry::new(#path, || Box::new(#ident)) }
     };
 
     TokenStream::from(expanded)
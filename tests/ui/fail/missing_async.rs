@@ -0,0 +1,8 @@
+include!("../support.rs");
+
+use russenger_macro::action;
+
+#[action]
+fn Main(_res: Res, _req: Req) {}
+
+fn main() {}
@@ -0,0 +1,12 @@
+include!("../support.rs");
+
+use russenger_macro::action;
+
+struct Bot;
+
+impl Bot {
+    #[action]
+    async fn Main(&self, _req: Req) {}
+}
+
+fn main() {}
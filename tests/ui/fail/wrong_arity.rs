@@ -0,0 +1,8 @@
+include!("../support.rs");
+
+use russenger_macro::action;
+
+#[action]
+async fn Main(_res: Res) {}
+
+fn main() {}
@@ -0,0 +1,16 @@
+include!("../support.rs");
+
+use russenger_macro::action;
+
+// A body ending in an `if` guard clause has no value-producing tail
+// expression; the macro must still append `Ok(())` rather than trying to
+// guess a `Result` out of the `if`. The early exit itself returns `Ok(())`,
+// since the body runs inside the `Result`-producing future.
+#[action]
+async fn Main(_res: Res, _req: Req) {
+    if false {
+        return Ok(());
+    }
+}
+
+fn main() {}
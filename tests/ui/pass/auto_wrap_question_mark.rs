@@ -0,0 +1,16 @@
+include!("../support.rs");
+
+use russenger_macro::action;
+
+fn parse(input: &str) -> error::Result<u8> {
+    input.parse::<u8>().map_err(|e| e.to_string())
+}
+
+// `?` inside a default `()`-return action should work without a manual
+// trailing `Ok(())`.
+#[action]
+async fn Main(_res: Res, _req: Req) {
+    let _n = parse("1")?;
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+include!("../support.rs");
+
+use russenger_macro::action;
+
+// Declaring `-> Result<()>` explicitly opts out of the auto-wrap; the body
+// is used as-is and must produce the `Result` itself.
+#[action]
+async fn Main(_res: Res, _req: Req) -> error::Result<()> {
+    Ok(())
+}
+
+fn main() {}
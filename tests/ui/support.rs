@@ -0,0 +1,31 @@
+// Minimal stand-ins for the `russenger` runtime types the `#[action]`
+// macro expands against, so these UI fixtures can compile on their own.
+
+pub mod error {
+    pub type Result<T> = std::result::Result<T, String>;
+}
+
+pub struct Res;
+pub struct Req;
+
+pub trait Action {
+    fn execute(
+        &self,
+        res: Res,
+        req: Req,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = error::Result<()>>>>;
+    fn path(&self) -> String;
+}
+
+pub struct ActionEntry {
+    pub path: &'static str,
+    pub constructor: fn() -> Box<dyn Action>,
+}
+
+impl ActionEntry {
+    pub const fn new(path: &'static str, constructor: fn() -> Box<dyn Action>) -> Self {
+        Self { path, constructor }
+    }
+}
+
+inventory::collect!(ActionEntry);